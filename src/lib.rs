@@ -28,6 +28,9 @@ use std::{
 #[derive(Debug)]
 pub struct ParsingError
 {
+    line: usize,
+    token: Option<usize>,
+    column: usize,
     input: String,
     error: num::ParseIntError,
 }
@@ -36,11 +39,17 @@ impl fmt::Display for ParsingError
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        write!(
+        write!(f, "line {}", self.line)?;
+        if let Some(token) = self.token {
+            write!(f, ", token {}", token)?;
+        }
+        writeln!(
             f,
-            "Could not parse \"{}\" to number: {:?}",
+            ": could not parse \"{}\" to number: {:?}",
             self.input, self.error
-        )
+        )?;
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{}^", " ".repeat(self.column))
     }
 }
 
@@ -51,6 +60,18 @@ pub enum ApplicationError
 {
     InputError(io::Error),
     ParsingError(ParsingError),
+    ParsingErrors(Vec<ParsingError>),
+    // A literal that does not fit in `Number` is already rejected by
+    // `as_number_at`, where `isize::from_str` yields a `ParseIntError`
+    // whose kind is `PosOverflow`/`NegOverflow`; only the running sum
+    // needs an extra guard.
+    #[cfg(feature = "checked-overflow")]
+    Overflow
+    {
+        line: usize,
+        running_total: Number,
+        addend: Number,
+    },
 }
 
 impl From<io::Error> for ApplicationError
@@ -71,21 +92,142 @@ impl From<ParsingError> for ApplicationError
 
 type Number = isize;
 
-fn as_number(line: &str) -> Result<Number, ParsingError>
+fn as_number_at(line: usize, input: &str) -> Result<Number, ParsingError>
+{
+    parse_token(line, None, input)
+}
+
+fn parse_token(
+    line: usize,
+    token: Option<usize>,
+    input: &str,
+) -> Result<Number, ParsingError>
 {
     // We cannot use From here because ParseIntError
-    // does not contain a reference to offending input.
-    line.trim().parse().map_err(|err| ParsingError {
-        input: String::from(line),
+    // does not contain a reference to offending input,
+    // nor the line it was read from.
+    input.trim().parse().map_err(|err| ParsingError {
+        line,
+        token,
+        column: offending_column(input),
+        input: String::from(input),
         error: err,
     })
 }
 
+// Locates the first character that cannot be part of an integer literal,
+// as a char offset into the original (untrimmed) input, so `Display` can
+// align a caret beneath it. Points just past the last character when the
+// whole body parsed as digits but still overflowed `Number`.
+fn offending_column(input: &str) -> usize
+{
+    let leading = input.chars().count() - input.trim_start().chars().count();
+    let mut column = 0;
+    for (i, c) in input.trim().chars().enumerate() {
+        if i == 0 && (c == '+' || c == '-') {
+            column = i + 1;
+            continue;
+        }
+        if !c.is_ascii_digit() {
+            column = i;
+            break;
+        }
+        column = i + 1;
+    }
+    leading + column
+}
+
+// Adds `addend` onto the running `total`. With `checked-overflow` enabled
+// this guards against wraparound and reports the offending `line`; without
+// it, summation uses plain `isize` addition, which panics on overflow in
+// debug builds and wraps around in release builds.
+#[cfg(feature = "checked-overflow")]
+fn accumulate(
+    total: Number,
+    addend: Number,
+    line: usize,
+) -> Result<Number, ApplicationError>
+{
+    total.checked_add(addend).ok_or(ApplicationError::Overflow {
+        line,
+        running_total: total,
+        addend,
+    })
+}
+
+#[cfg(not(feature = "checked-overflow"))]
+fn accumulate(
+    total: Number,
+    addend: Number,
+    _line: usize,
+) -> Result<Number, ApplicationError>
+{
+    Ok(total + addend)
+}
+
 pub fn sum<T>(lines: T) -> Result<Number, ApplicationError>
 where
     T: Iterator<Item = Result<String, io::Error>>,
 {
-    lines.map(|line| Ok(as_number(&line?)?)).sum()
+    let mut total: Number = 0;
+    for (i, line) in lines.enumerate() {
+        let line_no = i + 1;
+        total = accumulate(total, as_number_at(line_no, &line?)?, line_no)?;
+    }
+    Ok(total)
+}
+
+pub fn sum_checked<T>(lines: T) -> Result<Number, ApplicationError>
+where
+    T: Iterator<Item = Result<String, io::Error>>,
+{
+    let mut total: Number = 0;
+    let mut errors = Vec::new();
+    for (i, line) in lines.enumerate() {
+        let line_no = i + 1;
+        match as_number_at(line_no, &line?) {
+            Ok(n) => total = accumulate(total, n, line_no)?,
+            Err(e) => errors.push(e),
+        }
+    }
+    if errors.is_empty() {
+        Ok(total)
+    } else {
+        Err(ApplicationError::ParsingErrors(errors))
+    }
+}
+
+pub enum Separator
+{
+    Whitespace,
+    Char(char),
+}
+
+fn tokenize<'a>(line: &'a str, sep: &Separator) -> Vec<&'a str>
+{
+    match sep {
+        Separator::Whitespace => line.split_whitespace().collect(),
+        Separator::Char(c) => line
+            .split(|ch: char| ch.is_whitespace() || ch == *c)
+            .filter(|token| !token.is_empty())
+            .collect(),
+    }
+}
+
+pub fn sum_tokens<T>(lines: T, sep: Separator) -> Result<Number, ApplicationError>
+where
+    T: Iterator<Item = Result<String, io::Error>>,
+{
+    let mut total: Number = 0;
+    for (i, line) in lines.enumerate() {
+        let line_no = i + 1;
+        let line = line?;
+        for (j, token) in tokenize(&line, &sep).into_iter().enumerate() {
+            let addend = parse_token(line_no, Some(j + 1), token)?;
+            total = accumulate(total, addend, line_no)?;
+        }
+    }
+    Ok(total)
 }
 
 pub fn sum_strings<'a, T>(strings: T) -> Result<Number, ApplicationError>
@@ -103,19 +245,19 @@ mod tests
     #[test]
     fn parses_a_number()
     {
-        assert_eq!(as_number("42").unwrap(), 42);
+        assert_eq!(as_number_at(1, "42").unwrap(), 42);
     }
 
     #[test]
     fn parses_a_number_with_whitespace()
     {
-        assert_eq!(as_number("\t 42\n").unwrap(), 42);
+        assert_eq!(as_number_at(1, "\t 42\n").unwrap(), 42);
     }
 
     #[test]
     fn fails_on_invalid_character()
     {
-        let result = as_number(bad_input_char());
+        let result = as_number_at(1, bad_input_char());
         let msg = result.unwrap_err().to_string();
         assert!(
             msg.contains(bad_input_char()),
@@ -127,7 +269,7 @@ mod tests
     #[test]
     fn fails_on_empty_input()
     {
-        let msg = as_number("").unwrap_err().to_string();
+        let msg = as_number_at(1, "").unwrap_err().to_string();
         assert!(
             !msg.contains(bad_input_char()),
             "Unexpected (hardcoded?) text in error message \"{}\"",
@@ -172,6 +314,87 @@ mod tests
         sum(stream).unwrap_err();
     }
 
+    #[test]
+    fn sum_checked_reports_every_bad_line()
+    {
+        let stream = vec![
+            Ok(String::from("1")),
+            Ok(String::from("nope")),
+            Ok(String::from("3")),
+            Ok(String::from("$")),
+        ]
+        .into_iter();
+
+        let errors = match sum_checked(stream).unwrap_err() {
+            ApplicationError::ParsingErrors(errors) => errors,
+            other => panic!("Unexpected error variant: {:?}", other),
+        };
+
+        let lines: Vec<usize> = errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 4]);
+    }
+
+    #[test]
+    fn sum_checked_sums_when_all_lines_parse()
+    {
+        let stream =
+            vec![Ok(String::from("4")), Ok(String::from("5"))].into_iter();
+        assert_eq!(sum_checked(stream).unwrap(), 9);
+    }
+
+    #[test]
+    fn sums_whitespace_separated_tokens()
+    {
+        let stream = vec![Ok(String::from("10 20 30"))].into_iter();
+        assert_eq!(sum_tokens(stream, Separator::Whitespace).unwrap(), 60);
+    }
+
+    #[test]
+    fn sums_tokens_across_lines()
+    {
+        let stream =
+            vec![Ok(String::from("1, 2")), Ok(String::from("3, 4"))].into_iter();
+        assert_eq!(sum_tokens(stream, Separator::Char(',')).unwrap(), 10);
+    }
+
+    #[test]
+    fn token_failure_reports_line_and_token()
+    {
+        let stream = vec![Ok(String::from("10, 20, oops, 40"))].into_iter();
+        let err = sum_tokens(stream, Separator::Char(',')).unwrap_err();
+        let msg = match err {
+            ApplicationError::ParsingError(e) => e.to_string(),
+            other => panic!("Unexpected error variant: {:?}", other),
+        };
+        assert!(
+            msg.contains("token 3"),
+            "Token index missing from error message \"{}\"",
+            msg
+        );
+    }
+
+    #[test]
+    fn caret_points_at_offending_character()
+    {
+        let err = as_number_at(1, "12x4").unwrap_err();
+        assert_eq!(err.column, 2);
+
+        let rendered = err.to_string();
+        let caret_line = rendered.lines().last().unwrap();
+        assert_eq!(caret_line, "  ^");
+    }
+
+    #[test]
+    fn caret_sits_past_the_end_on_literal_overflow()
+    {
+        // A body that is all digits but larger than `Number` has no single
+        // offending character, so the caret intentionally lands one column
+        // past the last digit.
+        let input = "99999999999999999999999999999999";
+        let err = as_number_at(1, input).unwrap_err();
+        assert_eq!(err.column, input.chars().count());
+    }
+
     fn bad_input_char() -> &'static str
     {
         "$"
@@ -179,6 +402,6 @@ mod tests
 
     fn create_io_error() -> io::Error
     {
-        io::Error::new(io::ErrorKind::Other, "Mock Error")
+        io::Error::other("Mock Error")
     }
 }